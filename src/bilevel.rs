@@ -0,0 +1,191 @@
+//! Decoding of the legacy bilevel and grayscale TIFF tags used by fax and
+//! document-scan images: `Thresholding` (`0x0107`), `FillOrder` (`0x010A`)
+//! and `GrayResponseUnit` (`0x0122`).
+
+/// The technique used to convert from gray to black and white pixels, as
+/// given by the `Thresholding` tag.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Thresholding {
+    /// No dithering or halftoning has been applied (the default).
+    #[default]
+    NoDithering,
+    /// An ordered dither or halftone technique has been applied.
+    OrderedDither,
+    /// A randomized process such as error diffusion has been applied.
+    ErrorDiffusion,
+    /// A value not defined by the TIFF specification.
+    Other(u16),
+}
+
+impl Thresholding {
+    /// Decodes a `Thresholding` value.
+    pub const fn from_u16(value: u16) -> Self {
+        match value {
+            1 => Self::NoDithering,
+            2 => Self::OrderedDither,
+            3 => Self::ErrorDiffusion,
+            _ => Self::Other(value),
+        }
+    }
+
+    /// Encodes this back into a `Thresholding` value.
+    pub const fn to_u16(self) -> u16 {
+        match self {
+            Self::NoDithering => 1,
+            Self::OrderedDither => 2,
+            Self::ErrorDiffusion => 3,
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl From<u16> for Thresholding {
+    fn from(value: u16) -> Self {
+        Self::from_u16(value)
+    }
+}
+
+impl From<Thresholding> for u16 {
+    fn from(thresholding: Thresholding) -> Self {
+        thresholding.to_u16()
+    }
+}
+
+/// The logical order of bits within a byte of bit-packed image data, as given
+/// by the `FillOrder` tag.
+///
+/// Decoding with the wrong fill order mirrors every group of pixels packed
+/// into a byte, so an absent tag must be read as the default,
+/// [`HighBitFirst`](Self::HighBitFirst).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FillOrder {
+    /// Lower column values are stored in the higher-order bits of a byte
+    /// (the default).
+    #[default]
+    HighBitFirst,
+    /// Lower column values are stored in the lower-order bits of a byte.
+    LowBitFirst,
+    /// A value not defined by the TIFF specification.
+    Other(u16),
+}
+
+impl FillOrder {
+    /// Decodes a `FillOrder` value.
+    pub const fn from_u16(value: u16) -> Self {
+        match value {
+            1 => Self::HighBitFirst,
+            2 => Self::LowBitFirst,
+            _ => Self::Other(value),
+        }
+    }
+
+    /// Encodes this back into a `FillOrder` value.
+    pub const fn to_u16(self) -> u16 {
+        match self {
+            Self::HighBitFirst => 1,
+            Self::LowBitFirst => 2,
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl From<u16> for FillOrder {
+    fn from(value: u16) -> Self {
+        Self::from_u16(value)
+    }
+}
+
+impl From<FillOrder> for u16 {
+    fn from(fill_order: FillOrder) -> Self {
+        fill_order.to_u16()
+    }
+}
+
+/// The precision of the values in the `GrayResponseCurve` tag, as given by the
+/// `GrayResponseUnit` tag.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum GrayResponseUnit {
+    /// Values are in tenths of a unit.
+    Tenths,
+    /// Values are in hundredths of a unit (the default).
+    #[default]
+    Hundredths,
+    /// Values are in thousandths of a unit.
+    Thousandths,
+    /// Values are in ten-thousandths of a unit.
+    TenThousandths,
+    /// Values are in hundred-thousandths of a unit.
+    HundredThousandths,
+    /// A value not defined by the TIFF specification.
+    Other(u16),
+}
+
+impl GrayResponseUnit {
+    /// Decodes a `GrayResponseUnit` value.
+    pub const fn from_u16(value: u16) -> Self {
+        match value {
+            1 => Self::Tenths,
+            2 => Self::Hundredths,
+            3 => Self::Thousandths,
+            4 => Self::TenThousandths,
+            5 => Self::HundredThousandths,
+            _ => Self::Other(value),
+        }
+    }
+
+    /// Encodes this back into a `GrayResponseUnit` value.
+    pub const fn to_u16(self) -> u16 {
+        match self {
+            Self::Tenths => 1,
+            Self::Hundredths => 2,
+            Self::Thousandths => 3,
+            Self::TenThousandths => 4,
+            Self::HundredThousandths => 5,
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl From<u16> for GrayResponseUnit {
+    fn from(value: u16) -> Self {
+        Self::from_u16(value)
+    }
+}
+
+impl From<GrayResponseUnit> for u16 {
+    fn from(unit: GrayResponseUnit) -> Self {
+        unit.to_u16()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_both_fill_orders() {
+        assert_eq!(FillOrder::from_u16(1), FillOrder::HighBitFirst);
+        assert_eq!(FillOrder::from_u16(2), FillOrder::LowBitFirst);
+        assert_eq!(FillOrder::LowBitFirst.to_u16(), 2);
+        assert_eq!(FillOrder::default().to_u16(), 1);
+    }
+
+    #[test]
+    fn keeps_undefined_values() {
+        assert_eq!(FillOrder::from_u16(0), FillOrder::Other(0));
+        assert_eq!(u16::from(Thresholding::from(7)), 7);
+        assert_eq!(u16::from(GrayResponseUnit::from(6)), 6);
+    }
+
+    #[test]
+    fn round_trips_defined_values() {
+        for value in 1..=3 {
+            assert_eq!(Thresholding::from_u16(value).to_u16(), value);
+        }
+        for value in 1..=5 {
+            assert_eq!(GrayResponseUnit::from_u16(value).to_u16(), value);
+        }
+        assert_eq!(Thresholding::default().to_u16(), 1);
+        assert_eq!(GrayResponseUnit::default().to_u16(), 2);
+    }
+}
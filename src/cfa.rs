@@ -0,0 +1,109 @@
+//! Decoding of the DNG `CFALayout` (`0xC617`) tag.
+
+/// The spatial layout of the color filter array, as given by the `CFALayout`
+/// tag.
+///
+/// The staggered layouts describe sensors whose photosites are not on a
+/// rectangular grid, such as Fujifilm's SuperCCD; a demosaicer must account for
+/// the half-pixel offsets before applying the `CFAPattern`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CfaLayout {
+    /// A rectangular (or square) grid (the default).
+    #[default]
+    Rectangular,
+    /// Staggered layout A: even columns are offset down by half a row.
+    StaggerA,
+    /// Staggered layout B: even columns are offset up by half a row.
+    StaggerB,
+    /// Staggered layout C: even rows are offset right by half a column.
+    StaggerC,
+    /// Staggered layout D: even rows are offset left by half a column.
+    StaggerD,
+    /// Staggered layout E: even rows are offset up by half a row, even
+    /// columns are offset left by half a column (DNG 1.3).
+    StaggerE,
+    /// Staggered layout F: even rows are offset up by half a row, even
+    /// columns are offset right by half a column (DNG 1.3).
+    StaggerF,
+    /// Staggered layout G: even rows are offset down by half a row, even
+    /// columns are offset left by half a column (DNG 1.3).
+    StaggerG,
+    /// Staggered layout H: even rows are offset down by half a row, even
+    /// columns are offset right by half a column (DNG 1.3).
+    StaggerH,
+    /// A value not defined by the DNG specification.
+    Other(u16),
+}
+
+impl CfaLayout {
+    /// Decodes a `CFALayout` value.
+    pub const fn from_u16(value: u16) -> Self {
+        match value {
+            1 => Self::Rectangular,
+            2 => Self::StaggerA,
+            3 => Self::StaggerB,
+            4 => Self::StaggerC,
+            5 => Self::StaggerD,
+            6 => Self::StaggerE,
+            7 => Self::StaggerF,
+            8 => Self::StaggerG,
+            9 => Self::StaggerH,
+            _ => Self::Other(value),
+        }
+    }
+
+    /// Encodes this back into a `CFALayout` value.
+    pub const fn to_u16(self) -> u16 {
+        match self {
+            Self::Rectangular => 1,
+            Self::StaggerA => 2,
+            Self::StaggerB => 3,
+            Self::StaggerC => 4,
+            Self::StaggerD => 5,
+            Self::StaggerE => 6,
+            Self::StaggerF => 7,
+            Self::StaggerG => 8,
+            Self::StaggerH => 9,
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl From<u16> for CfaLayout {
+    fn from(value: u16) -> Self {
+        Self::from_u16(value)
+    }
+}
+
+impl From<CfaLayout> for u16 {
+    fn from(layout: CfaLayout) -> Self {
+        layout.to_u16()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_rectangular() {
+        assert_eq!(CfaLayout::from_u16(1), CfaLayout::Rectangular);
+        assert_eq!(CfaLayout::default().to_u16(), 1);
+    }
+
+    #[test]
+    fn round_trips_defined_values() {
+        for value in 1..=9 {
+            let layout = CfaLayout::from(value);
+            assert!(!matches!(layout, CfaLayout::Other(_)));
+            assert_eq!(u16::from(layout), value);
+        }
+        assert_eq!(CfaLayout::from_u16(2), CfaLayout::StaggerA);
+    }
+
+    #[test]
+    fn keeps_undefined_values() {
+        assert_eq!(CfaLayout::from_u16(0), CfaLayout::Other(0));
+        assert_eq!(CfaLayout::Other(10).to_u16(), 10);
+    }
+}
@@ -0,0 +1,77 @@
+//! Decoding of the DNG `ProfileHueSatMapEncoding` (`0xC7A3`) and
+//! `ProfileLookTableEncoding` (`0xC7A4`) tags.
+
+/// How the value axis of a 3D hue/saturation/value table is indexed, as given
+/// by the `ProfileHueSatMapEncoding` and `ProfileLookTableEncoding` tags.
+///
+/// The encoding only matters for 3D tables, i.e. when the value dimension of
+/// `ProfileHueSatMapDims` (or `ProfileLookTableDims`) is greater than 1; 2D
+/// tables have a single value division and are unaffected.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum HueSatMapEncoding {
+    /// The value axis is indexed linearly (the default).
+    #[default]
+    Linear,
+    /// The value axis is indexed after applying the sRGB gamma curve.
+    Srgb,
+    /// A value not defined by the DNG specification.
+    Other(u32),
+}
+
+impl HueSatMapEncoding {
+    /// Decodes a `ProfileHueSatMapEncoding` or `ProfileLookTableEncoding`
+    /// value.
+    pub const fn from_u32(value: u32) -> Self {
+        match value {
+            0 => Self::Linear,
+            1 => Self::Srgb,
+            _ => Self::Other(value),
+        }
+    }
+
+    /// Encodes this back into a `ProfileHueSatMapEncoding` or
+    /// `ProfileLookTableEncoding` value.
+    pub const fn to_u32(self) -> u32 {
+        match self {
+            Self::Linear => 0,
+            Self::Srgb => 1,
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl From<u32> for HueSatMapEncoding {
+    fn from(value: u32) -> Self {
+        Self::from_u32(value)
+    }
+}
+
+impl From<HueSatMapEncoding> for u32 {
+    fn from(encoding: HueSatMapEncoding) -> Self {
+        encoding.to_u32()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_linear() {
+        assert_eq!(HueSatMapEncoding::from_u32(0), HueSatMapEncoding::Linear);
+        assert_eq!(HueSatMapEncoding::default(), HueSatMapEncoding::Linear);
+        assert_eq!(HueSatMapEncoding::Linear.to_u32(), 0);
+    }
+
+    #[test]
+    fn decodes_srgb() {
+        assert_eq!(HueSatMapEncoding::from_u32(1), HueSatMapEncoding::Srgb);
+        assert_eq!(u32::from(HueSatMapEncoding::Srgb), 1);
+    }
+
+    #[test]
+    fn keeps_undefined_values() {
+        assert_eq!(HueSatMapEncoding::from(2), HueSatMapEncoding::Other(2));
+        assert_eq!(HueSatMapEncoding::Other(2).to_u32(), 2);
+    }
+}
@@ -0,0 +1,181 @@
+//! Minimal reading of embedded ICC color profiles.
+//!
+//! Images may carry an ICC profile as an opaque byte blob (for example in the
+//! `InterColorProfile`, `AsShotICCProfile` and `CurrentICCProfile` tags). This
+//! module does not implement an ICC parser; it only reads enough of the
+//! profile to show its name to a user.
+
+/// Size of the fixed ICC profile header, after which the tag table starts.
+const HEADER_SIZE: usize = 128;
+
+/// Offset of the `acsp` profile file signature within the header.
+const SIGNATURE_OFFSET: usize = 36;
+
+/// Size of one entry in the ICC tag table (signature, offset and size).
+const TAG_ENTRY_SIZE: usize = 12;
+
+/// Returns whether `bytes` starts with a valid ICC profile header, i.e. it is
+/// long enough to hold the header and carries the `acsp` file signature.
+pub fn is_icc_profile(bytes: &[u8]) -> bool {
+    bytes.get(SIGNATURE_OFFSET..SIGNATURE_OFFSET + 4) == Some(b"acsp") && bytes.len() >= HEADER_SIZE
+}
+
+/// Returns the profile description (the ICC `desc` tag) of the ICC profile in
+/// `bytes`.
+///
+/// Both the ICC v2 `textDescriptionType` (ASCII) and the ICC v4
+/// `multiLocalizedUnicodeType` (UTF-16BE, first record is used) encodings are
+/// supported. Returns `None` if `bytes` is not an ICC profile, has no `desc`
+/// tag, or the tag is malformed.
+pub fn icc_profile_description(bytes: &[u8]) -> Option<String> {
+    if !is_icc_profile(bytes) {
+        return None;
+    }
+
+    // The tag count comes from the file, so only walk the entries that fit.
+    let tag_count = read_u32(bytes, HEADER_SIZE)? as usize;
+    let table = bytes.get(HEADER_SIZE + 4..)?;
+    let desc = table
+        .chunks_exact(TAG_ENTRY_SIZE)
+        .take(tag_count)
+        .find(|entry| entry.starts_with(b"desc"))?;
+
+    let offset = read_u32(desc, 4)? as usize;
+    let size = read_u32(desc, 8)? as usize;
+    let desc = bytes.get(offset..offset.checked_add(size)?)?;
+
+    match desc.get(..4)? {
+        b"desc" => read_text_description(desc),
+        b"mluc" => read_multi_localized_unicode(desc),
+        _ => None,
+    }
+}
+
+/// Reads the ASCII string of an ICC v2 `textDescriptionType` element.
+fn read_text_description(desc: &[u8]) -> Option<String> {
+    let count = read_u32(desc, 8)? as usize;
+    let text = desc.get(12..12usize.checked_add(count)?)?;
+    let text = text.split(|&byte| byte == 0).next()?;
+
+    std::str::from_utf8(text)
+        .ok()
+        .filter(|text| text.is_ascii())
+        .map(str::to_owned)
+}
+
+/// Reads the first record of an ICC v4 `multiLocalizedUnicodeType` element.
+fn read_multi_localized_unicode(desc: &[u8]) -> Option<String> {
+    let record_count = read_u32(desc, 8)?;
+    let record_size = read_u32(desc, 12)?;
+    if record_count == 0 || record_size < 12 {
+        return None;
+    }
+
+    let length = read_u32(desc, 20)? as usize;
+    let offset = read_u32(desc, 24)? as usize;
+    let text = desc.get(offset..offset.checked_add(length)?)?;
+    let units = text
+        .chunks_exact(2)
+        .map(|unit| u16::from_be_bytes([unit[0], unit[1]]))
+        .take_while(|&unit| unit != 0);
+
+    char::decode_utf16(units).collect::<Result<_, _>>().ok()
+}
+
+/// Reads a big-endian `u32` at `offset`, as all ICC profile fields are stored.
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    let field = bytes.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_be_bytes(field.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a minimal profile holding a single `desc` tag with `element`.
+    fn profile_with_desc(element: &[u8]) -> Vec<u8> {
+        let mut profile = vec![0; HEADER_SIZE];
+        profile[SIGNATURE_OFFSET..SIGNATURE_OFFSET + 4].copy_from_slice(b"acsp");
+        profile.extend_from_slice(&1u32.to_be_bytes());
+
+        let offset = (profile.len() + TAG_ENTRY_SIZE) as u32;
+        profile.extend_from_slice(b"desc");
+        profile.extend_from_slice(&offset.to_be_bytes());
+        profile.extend_from_slice(&(element.len() as u32).to_be_bytes());
+        profile.extend_from_slice(element);
+
+        let size = profile.len() as u32;
+        profile[..4].copy_from_slice(&size.to_be_bytes());
+        profile
+    }
+
+    #[test]
+    fn reads_v2_srgb_description() {
+        let mut element = b"desc\0\0\0\0".to_vec();
+        element.extend_from_slice(&18u32.to_be_bytes());
+        element.extend_from_slice(b"sRGB IEC61966-2.1\0");
+        element.extend_from_slice(&[0; 12]);
+
+        let profile = profile_with_desc(&element);
+        assert_eq!(
+            icc_profile_description(&profile).as_deref(),
+            Some("sRGB IEC61966-2.1"),
+        );
+    }
+
+    #[test]
+    fn reads_v4_multi_localized_description() {
+        let mut element = b"mluc\0\0\0\0".to_vec();
+        element.extend_from_slice(&1u32.to_be_bytes());
+        element.extend_from_slice(&12u32.to_be_bytes());
+        element.extend_from_slice(b"enUS");
+        element.extend_from_slice(&8u32.to_be_bytes());
+        element.extend_from_slice(&28u32.to_be_bytes());
+        element.extend(b"sRGB".iter().flat_map(|&c| [0, c]));
+
+        let profile = profile_with_desc(&element);
+        assert_eq!(icc_profile_description(&profile).as_deref(), Some("sRGB"));
+    }
+
+    #[test]
+    fn rejects_non_ascii_v2_description() {
+        let mut element = b"desc\0\0\0\0".to_vec();
+        element.extend_from_slice(&6u32.to_be_bytes());
+        element.extend_from_slice("Café\0".as_bytes());
+
+        let profile = profile_with_desc(&element);
+        assert_eq!(icc_profile_description(&profile), None);
+    }
+
+    #[test]
+    fn rejects_missing_signature() {
+        let mut element = b"desc\0\0\0\0".to_vec();
+        element.extend_from_slice(&5u32.to_be_bytes());
+        element.extend_from_slice(b"sRGB\0");
+
+        let mut profile = profile_with_desc(&element);
+        profile[SIGNATURE_OFFSET..SIGNATURE_OFFSET + 4].copy_from_slice(b"xxxx");
+        assert!(!is_icc_profile(&profile));
+        assert_eq!(icc_profile_description(&profile), None);
+    }
+
+    #[test]
+    fn rejects_truncated_profile() {
+        let mut element = b"desc\0\0\0\0".to_vec();
+        element.extend_from_slice(&18u32.to_be_bytes());
+        element.extend_from_slice(b"sRGB IEC61966-2.1\0");
+
+        let profile = profile_with_desc(&element);
+        assert_eq!(icc_profile_description(&profile[..profile.len() - 4]), None);
+        assert_eq!(icc_profile_description(&profile[..HEADER_SIZE + 2]), None);
+    }
+
+    #[test]
+    fn bounds_huge_tag_count() {
+        let mut profile = vec![0; HEADER_SIZE];
+        profile[SIGNATURE_OFFSET..SIGNATURE_OFFSET + 4].copy_from_slice(b"acsp");
+        profile.extend_from_slice(&u32::MAX.to_be_bytes());
+
+        assert_eq!(icc_profile_description(&profile), None);
+    }
+}
@@ -1,3 +1,18 @@
+pub mod bilevel;
+pub mod cfa;
+pub mod hue_sat_map;
+pub mod icc;
+pub mod matrix;
+pub mod palette;
+pub mod photometric;
+pub mod predictor;
+pub mod preview;
+pub mod sensitivity;
+pub mod separation;
+pub mod subfile;
+pub mod tone_curve;
+pub mod types;
+
 pub fn add(left: usize, right: usize) -> usize {
     left + right
 }
@@ -0,0 +1,73 @@
+//! 3×3 matrix math for the DNG color matrices, such as `ColorMatrix1`
+//! (`0xC621`), `CameraCalibration1` (`0xC623`) and `ForwardMatrix1`
+//! (`0xC714`).
+
+/// Returns the inverse of `m`.
+///
+/// `ColorMatrix` tags map XYZ to camera space, so they must be inverted to
+/// map camera values to XYZ.
+///
+/// Returns `None` if `m` is singular, or if its determinant or inverse is not
+/// finite (for example because `m` holds NaN or infinite values).
+pub fn invert_3x3(m: [[f64; 3]; 3]) -> Option<[[f64; 3]; 3]> {
+    // Cofactors of the first row; the rest are computed below.
+    let c00 = m[1][1] * m[2][2] - m[1][2] * m[2][1];
+    let c01 = m[1][2] * m[2][0] - m[1][0] * m[2][2];
+    let c02 = m[1][0] * m[2][1] - m[1][1] * m[2][0];
+    let det = m[0][0] * c00 + m[0][1] * c01 + m[0][2] * c02;
+    if det == 0.0 || !det.is_finite() {
+        return None;
+    }
+    let inverse = [
+        [
+            c00 / det,
+            (m[0][2] * m[2][1] - m[0][1] * m[2][2]) / det,
+            (m[0][1] * m[1][2] - m[0][2] * m[1][1]) / det,
+        ],
+        [
+            c01 / det,
+            (m[0][0] * m[2][2] - m[0][2] * m[2][0]) / det,
+            (m[0][2] * m[1][0] - m[0][0] * m[1][2]) / det,
+        ],
+        [
+            c02 / det,
+            (m[0][1] * m[2][0] - m[0][0] * m[2][1]) / det,
+            (m[0][0] * m[1][1] - m[0][1] * m[1][0]) / det,
+        ],
+    ];
+    inverse
+        .iter()
+        .flatten()
+        .all(|value| value.is_finite())
+        .then_some(inverse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverts_known_matrix() {
+        let m = [[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]];
+        assert_eq!(
+            invert_3x3(m),
+            Some([[-24.0, 18.0, 5.0], [20.0, -15.0, -4.0], [-5.0, 4.0, 1.0]])
+        );
+    }
+
+    #[test]
+    fn inverts_identity() {
+        let identity = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        assert_eq!(invert_3x3(identity), Some(identity));
+    }
+
+    #[test]
+    fn rejects_singular_and_non_finite_matrices() {
+        let singular = [[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]];
+        assert_eq!(invert_3x3(singular), None);
+        let nan = [[f64::NAN, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        assert_eq!(invert_3x3(nan), None);
+        let huge = [[f64::MAX, 0.0, 0.0], [0.0, f64::MAX, 0.0], [0.0, 0.0, 1.0]];
+        assert_eq!(invert_3x3(huge), None);
+    }
+}
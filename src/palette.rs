@@ -0,0 +1,102 @@
+//! Decoding of palette-color (`PhotometricInterpretation` = 3) images.
+
+/// Decodes the `ColorMap` (`0x0140`) tag into one `(red, green, blue)` triple
+/// per palette index.
+///
+/// `values` is the tag's flat array of `3 * 2^bits_per_sample` entries: all
+/// red values, then all green values, then all blue values, with index `i` of
+/// the palette at position `i` of each third.
+///
+/// Returns `None` if `bits_per_sample` is greater than 16 (the palette index
+/// would not fit the `Short` pixel values) or `values` does not hold exactly
+/// `3 * 2^bits_per_sample` entries.
+pub fn decode_color_map(values: &[u16], bits_per_sample: u16) -> Option<Vec<(u16, u16, u16)>> {
+    if bits_per_sample > 16 {
+        return None;
+    }
+
+    let entries = 1usize.checked_shl(u32::from(bits_per_sample))?;
+    if values.len() != entries.checked_mul(3)? {
+        return None;
+    }
+
+    let (reds, rest) = values.split_at(entries);
+    let (greens, blues) = rest.split_at(entries);
+    let color_map = reds
+        .iter()
+        .zip(greens)
+        .zip(blues)
+        .map(|((&red, &green), &blue)| (red, green, blue))
+        .collect();
+
+    Some(color_map)
+}
+
+/// Returns the RGB triple that the palette pixel `index` refers to in
+/// `color_map`, as decoded by [`decode_color_map`].
+///
+/// Returns `None` if `index` is past the end of `color_map`, which a
+/// malformed file can cause since pixel data is not checked against the
+/// palette size.
+pub fn resolve_palette_pixel(index: u16, color_map: &[(u16, u16, u16)]) -> Option<(u16, u16, u16)> {
+    color_map.get(usize::from(index)).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The flat `ColorMap` values of a 4-bit palette where entry `i` is
+    /// `(i, 0x100 + i, 0x200 + i)`.
+    fn color_map_values() -> Vec<u16> {
+        (0..16).chain(0x100..0x110).chain(0x200..0x210).collect()
+    }
+
+    #[test]
+    fn decodes_4_bit_palette() {
+        let color_map = decode_color_map(&color_map_values(), 4).unwrap();
+        assert_eq!(color_map.len(), 16);
+        assert_eq!(color_map[0], (0, 0x100, 0x200));
+        assert_eq!(color_map[9], (9, 0x109, 0x209));
+        assert_eq!(color_map[15], (15, 0x10F, 0x20F));
+    }
+
+    #[test]
+    fn rejects_wrong_length() {
+        let values = color_map_values();
+        assert_eq!(decode_color_map(&values[..47], 4), None);
+        assert_eq!(decode_color_map(&values, 3), None);
+        assert_eq!(decode_color_map(&values, 5), None);
+        assert_eq!(decode_color_map(&[], 0), None);
+    }
+
+    #[test]
+    fn rejects_oversized_bit_depth() {
+        assert_eq!(decode_color_map(&[0; 3], 17), None);
+        assert_eq!(decode_color_map(&[0; 3], u16::MAX), None);
+    }
+
+    #[test]
+    fn resolves_indices() {
+        let color_map = decode_color_map(&color_map_values(), 4).unwrap();
+        assert_eq!(
+            resolve_palette_pixel(0, &color_map),
+            Some((0, 0x100, 0x200))
+        );
+        assert_eq!(
+            resolve_palette_pixel(5, &color_map),
+            Some((5, 0x105, 0x205))
+        );
+        assert_eq!(
+            resolve_palette_pixel(15, &color_map),
+            Some((15, 0x10F, 0x20F))
+        );
+    }
+
+    #[test]
+    fn rejects_out_of_bounds_index() {
+        let color_map = decode_color_map(&color_map_values(), 4).unwrap();
+        assert_eq!(resolve_palette_pixel(16, &color_map), None);
+        assert_eq!(resolve_palette_pixel(0, &[]), None);
+    }
+}
@@ -0,0 +1,156 @@
+//! Decoding of the `PhotometricInterpretation` (`0x0106`) tag.
+
+/// The color space of the image data, as given by the
+/// `PhotometricInterpretation` tag.
+///
+/// TIFF and DNG decoders branch on this to decide how to interpret samples,
+/// e.g. [`Cfa`](Self::Cfa) and [`LinearRaw`](Self::LinearRaw) hold sensor data
+/// rather than a rendered image.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PhotometricInterpretation {
+    /// Bilevel or grayscale, with 0 imaged as white.
+    WhiteIsZero,
+    /// Bilevel or grayscale, with 0 imaged as black.
+    BlackIsZero,
+    /// Red, green and blue samples.
+    Rgb,
+    /// Indexes into the `ColorMap` palette.
+    Palette,
+    /// A transparency mask for another image in the file.
+    TransparencyMask,
+    /// Ink separations, usually cyan, magenta, yellow and black.
+    Cmyk,
+    /// Luminance and chrominance samples.
+    YCbCr,
+    /// 1976 CIE L*a*b* samples.
+    CieLab,
+    /// Raw sensor data behind a color filter array (DNG).
+    Cfa,
+    /// Linear raw sensor data that has already been demosaiced (DNG).
+    LinearRaw,
+    /// A value not listed above.
+    Other(u16),
+}
+
+impl PhotometricInterpretation {
+    /// Decodes a `PhotometricInterpretation` value.
+    pub const fn from_u16(value: u16) -> Self {
+        match value {
+            0 => Self::WhiteIsZero,
+            1 => Self::BlackIsZero,
+            2 => Self::Rgb,
+            3 => Self::Palette,
+            4 => Self::TransparencyMask,
+            5 => Self::Cmyk,
+            6 => Self::YCbCr,
+            8 => Self::CieLab,
+            32803 => Self::Cfa,
+            34892 => Self::LinearRaw,
+            _ => Self::Other(value),
+        }
+    }
+
+    /// Encodes this back into a `PhotometricInterpretation` value.
+    pub const fn to_u16(self) -> u16 {
+        match self {
+            Self::WhiteIsZero => 0,
+            Self::BlackIsZero => 1,
+            Self::Rgb => 2,
+            Self::Palette => 3,
+            Self::TransparencyMask => 4,
+            Self::Cmyk => 5,
+            Self::YCbCr => 6,
+            Self::CieLab => 8,
+            Self::Cfa => 32803,
+            Self::LinearRaw => 34892,
+            Self::Other(value) => value,
+        }
+    }
+
+    /// Returns whether the image holds raw sensor data, i.e. is
+    /// [`Cfa`](Self::Cfa) or [`LinearRaw`](Self::LinearRaw).
+    pub const fn is_raw(self) -> bool {
+        matches!(self, Self::Cfa | Self::LinearRaw)
+    }
+
+    /// Returns whether the image is a rendered color image, i.e. is
+    /// [`Rgb`](Self::Rgb) or [`YCbCr`](Self::YCbCr).
+    pub const fn is_rendered(self) -> bool {
+        matches!(self, Self::Rgb | Self::YCbCr)
+    }
+}
+
+impl From<u16> for PhotometricInterpretation {
+    fn from(value: u16) -> Self {
+        Self::from_u16(value)
+    }
+}
+
+impl From<PhotometricInterpretation> for u16 {
+    fn from(interpretation: PhotometricInterpretation) -> Self {
+        interpretation.to_u16()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_rgb_palette_and_cfa() {
+        assert_eq!(
+            PhotometricInterpretation::from_u16(2),
+            PhotometricInterpretation::Rgb
+        );
+        assert_eq!(
+            PhotometricInterpretation::from_u16(3),
+            PhotometricInterpretation::Palette
+        );
+        assert_eq!(
+            PhotometricInterpretation::from_u16(32803),
+            PhotometricInterpretation::Cfa
+        );
+    }
+
+    #[test]
+    fn round_trips_values() {
+        for value in [0, 1, 2, 3, 4, 5, 6, 8, 32803, 34892] {
+            let interpretation = PhotometricInterpretation::from(value);
+            assert!(!matches!(
+                interpretation,
+                PhotometricInterpretation::Other(_)
+            ));
+            assert_eq!(u16::from(interpretation), value);
+        }
+        assert_eq!(
+            PhotometricInterpretation::from_u16(7),
+            PhotometricInterpretation::Other(7)
+        );
+        assert_eq!(PhotometricInterpretation::Other(7).to_u16(), 7);
+    }
+
+    #[test]
+    fn classifies_raw_and_rendered() {
+        for raw in [
+            PhotometricInterpretation::Cfa,
+            PhotometricInterpretation::LinearRaw,
+        ] {
+            assert!(raw.is_raw());
+            assert!(!raw.is_rendered());
+        }
+        for rendered in [
+            PhotometricInterpretation::Rgb,
+            PhotometricInterpretation::YCbCr,
+        ] {
+            assert!(rendered.is_rendered());
+            assert!(!rendered.is_raw());
+        }
+        for other in [
+            PhotometricInterpretation::Palette,
+            PhotometricInterpretation::Other(7),
+        ] {
+            assert!(!other.is_raw());
+            assert!(!other.is_rendered());
+        }
+    }
+}
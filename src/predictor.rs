@@ -0,0 +1,173 @@
+//! Reversal of the TIFF `Predictor` (`0x013D`) applied before compression.
+//!
+//! This crate does not decompress image data. These helpers finish the pixel
+//! reconstruction for users who decompressed strips or tiles elsewhere.
+
+use std::error::Error;
+use std::fmt;
+
+/// Undoes horizontal differencing (`Predictor` = 2) in place.
+///
+/// `buf` holds whole rows of `width` pixels with `samples` interleaved samples
+/// each (chunky planar configuration; pass `samples` = 1 for each plane of a
+/// planar image). Within each row, every sample is stored as the difference
+/// from the same sample of the previous pixel; this adds them back up with
+/// wrapping arithmetic. Trailing bytes that do not form a whole row are left
+/// untouched.
+///
+/// 16- and 32-bit samples must be in native byte order, i.e. already swapped
+/// from the file's byte order if the two differ.
+///
+/// # Errors
+///
+/// Returns [`UnsupportedBitsPerSample`], leaving `buf` untouched, if
+/// `bits_per_sample` is not 8, 16 or 32.
+pub fn reverse_horizontal_predictor(
+    buf: &mut [u8],
+    width: usize,
+    samples: usize,
+    bits_per_sample: u8,
+) -> Result<(), UnsupportedBitsPerSample> {
+    let sample_size = match bits_per_sample {
+        8 | 16 | 32 => usize::from(bits_per_sample / 8),
+        _ => return Err(UnsupportedBitsPerSample(bits_per_sample)),
+    };
+    // A row size that overflows cannot fit in `buf`, so there is no whole
+    // row to process.
+    let Some(row_samples) = width.checked_mul(samples) else {
+        return Ok(());
+    };
+    let Some(row_size) = row_samples.checked_mul(sample_size) else {
+        return Ok(());
+    };
+    if row_size == 0 {
+        return Ok(());
+    }
+
+    for row in buf.chunks_exact_mut(row_size) {
+        for index in samples..row_samples {
+            let previous = read_sample(row, index - samples, sample_size);
+            let current = read_sample(row, index, sample_size);
+            write_sample(row, index, sample_size, current.wrapping_add(previous));
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads the native-endian sample at sample `index` of `row`.
+fn read_sample(row: &[u8], index: usize, sample_size: usize) -> u32 {
+    let bytes = &row[index * sample_size..][..sample_size];
+    match sample_size {
+        1 => u32::from(bytes[0]),
+        2 => u32::from(u16::from_ne_bytes([bytes[0], bytes[1]])),
+        _ => u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+    }
+}
+
+/// Writes `value`, truncated to the sample size, as the native-endian sample
+/// at sample `index` of `row`.
+fn write_sample(row: &mut [u8], index: usize, sample_size: usize, value: u32) {
+    let bytes = &mut row[index * sample_size..][..sample_size];
+    match sample_size {
+        1 => bytes[0] = value as u8,
+        2 => bytes.copy_from_slice(&(value as u16).to_ne_bytes()),
+        _ => bytes.copy_from_slice(&value.to_ne_bytes()),
+    }
+}
+
+/// The error returned when undoing a predictor for a sample size this crate
+/// does not support.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UnsupportedBitsPerSample(pub u8);
+
+impl fmt::Display for UnsupportedBitsPerSample {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unsupported bits per sample for horizontal predictor: {}",
+            self.0
+        )
+    }
+}
+
+impl Error for UnsupportedBitsPerSample {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reverses_8_bit_rgb() {
+        #[rustfmt::skip]
+        let mut buf = vec![
+            10, 20, 30, 1, 2, 3, 255, 0, 1,
+            100, 100, 100, 0, 0, 0, 156, 156, 156,
+        ];
+        reverse_horizontal_predictor(&mut buf, 3, 3, 8).unwrap();
+
+        #[rustfmt::skip]
+        let expected = vec![
+            10, 20, 30, 11, 22, 33, 10, 22, 34,
+            100, 100, 100, 100, 100, 100, 0, 0, 0,
+        ];
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn reverses_16_bit_single_channel() {
+        let differences: [u16; 6] = [1000, 1, 65535, 500, 40000, 30000];
+        let mut buf: Vec<u8> = differences.iter().flat_map(|s| s.to_ne_bytes()).collect();
+        reverse_horizontal_predictor(&mut buf, 3, 1, 16).unwrap();
+
+        let samples: Vec<u16> = buf
+            .chunks_exact(2)
+            .map(|s| u16::from_ne_bytes([s[0], s[1]]))
+            .collect();
+        assert_eq!(samples, [1000, 1001, 1000, 500, 40500, 4964]);
+    }
+
+    #[test]
+    fn leaves_partial_row_untouched() {
+        let mut buf = vec![1, 1, 1, 1, 1];
+        reverse_horizontal_predictor(&mut buf, 2, 1, 8).unwrap();
+        assert_eq!(buf, [1, 2, 1, 2, 1]);
+    }
+
+    #[test]
+    fn ignores_overflowing_row_size() {
+        let mut buf = [1, 2, 3, 4];
+        assert_eq!(
+            reverse_horizontal_predictor(&mut buf, usize::MAX, 3, 8),
+            Ok(())
+        );
+        assert_eq!(
+            reverse_horizontal_predictor(&mut buf, usize::MAX / 2, 1, 32),
+            Ok(())
+        );
+        assert_eq!(buf, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn reverses_32_bit_two_channels() {
+        let differences: [u32; 4] = [7, u32::MAX, 1, 2];
+        let mut buf: Vec<u8> = differences.iter().flat_map(|s| s.to_ne_bytes()).collect();
+        reverse_horizontal_predictor(&mut buf, 2, 2, 32).unwrap();
+
+        let samples: Vec<u32> = buf
+            .chunks_exact(4)
+            .map(|s| u32::from_ne_bytes([s[0], s[1], s[2], s[3]]))
+            .collect();
+        assert_eq!(samples, [7, u32::MAX, 8, 1]);
+    }
+
+    #[test]
+    fn rejects_unsupported_bit_depth() {
+        let mut buf = [1, 2, 3, 4];
+        assert_eq!(
+            reverse_horizontal_predictor(&mut buf, 4, 1, 4),
+            Err(UnsupportedBitsPerSample(4)),
+        );
+        assert_eq!(buf, [1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,61 @@
+//! Scaling of preview samples by the DNG `RawToPreviewGain` (`0xC7A8`) tag.
+
+use std::error::Error;
+use std::fmt;
+
+/// Multiplies every sample in `samples` by `gain`.
+///
+/// `RawToPreviewGain` is the gain that maps raw-space sample values to the
+/// values of a preview IFD, so applying it moves samples from raw space to
+/// preview space; applying `1.0 / gain` moves them back.
+///
+/// # Errors
+///
+/// Returns [`InvalidPreviewGain`], leaving `samples` untouched, if `gain` is
+/// not finite or not positive, since such a gain cannot be reversed.
+pub fn apply_preview_gain(samples: &mut [f32], gain: f64) -> Result<(), InvalidPreviewGain> {
+    if !gain.is_finite() || gain <= 0.0 {
+        return Err(InvalidPreviewGain(gain));
+    }
+    for sample in samples {
+        *sample = (f64::from(*sample) * gain) as f32;
+    }
+    Ok(())
+}
+
+/// The error returned when applying a `RawToPreviewGain` that is not a finite,
+/// positive number.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InvalidPreviewGain(pub f64);
+
+impl fmt::Display for InvalidPreviewGain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid raw-to-preview gain: {}", self.0)
+    }
+}
+
+impl Error for InvalidPreviewGain {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_scaling() {
+        let raw = [0.0, 0.125, 0.5, 1.0];
+        let mut samples = raw;
+        apply_preview_gain(&mut samples, 2.0).unwrap();
+        assert_eq!(samples, [0.0, 0.25, 1.0, 2.0]);
+        apply_preview_gain(&mut samples, 1.0 / 2.0).unwrap();
+        assert_eq!(samples, raw);
+    }
+
+    #[test]
+    fn rejects_irreversible_gains() {
+        let mut samples = [1.0];
+        for gain in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(apply_preview_gain(&mut samples, gain).is_err());
+        }
+        assert_eq!(samples, [1.0]);
+    }
+}
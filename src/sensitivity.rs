@@ -0,0 +1,105 @@
+//! Decoding of the Exif `SensitivityType` (`0x8830`) tag.
+
+/// Which of the Exif 2.3 sensitivity parameters a file records, as given by
+/// the `SensitivityType` tag.
+///
+/// The named parameters are stored in `StandardOutputSensitivity`
+/// (`0x8831`), `RecommendedExposureIndex` (`0x8832`) and `ISOSpeed`
+/// (`0x8833`); `PhotographicSensitivity` (`0x8827`) holds the value of the
+/// first one listed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SensitivityType {
+    /// The sensitivity type is unknown (the default).
+    #[default]
+    Unknown,
+    /// Standard output sensitivity (SOS).
+    StandardOutputSensitivity,
+    /// Recommended exposure index (REI).
+    RecommendedExposureIndex,
+    /// ISO speed.
+    IsoSpeed,
+    /// Standard output sensitivity and recommended exposure index.
+    SosAndRei,
+    /// Standard output sensitivity and ISO speed.
+    SosAndIsoSpeed,
+    /// Recommended exposure index and ISO speed.
+    ReiAndIsoSpeed,
+    /// Standard output sensitivity, recommended exposure index and ISO speed.
+    SosReiAndIsoSpeed,
+    /// A value not defined by the Exif specification.
+    Other(u16),
+}
+
+impl SensitivityType {
+    /// Decodes a `SensitivityType` value.
+    pub const fn from_u16(value: u16) -> Self {
+        match value {
+            0 => Self::Unknown,
+            1 => Self::StandardOutputSensitivity,
+            2 => Self::RecommendedExposureIndex,
+            3 => Self::IsoSpeed,
+            4 => Self::SosAndRei,
+            5 => Self::SosAndIsoSpeed,
+            6 => Self::ReiAndIsoSpeed,
+            7 => Self::SosReiAndIsoSpeed,
+            _ => Self::Other(value),
+        }
+    }
+
+    /// Encodes this back into a `SensitivityType` value.
+    pub const fn to_u16(self) -> u16 {
+        match self {
+            Self::Unknown => 0,
+            Self::StandardOutputSensitivity => 1,
+            Self::RecommendedExposureIndex => 2,
+            Self::IsoSpeed => 3,
+            Self::SosAndRei => 4,
+            Self::SosAndIsoSpeed => 5,
+            Self::ReiAndIsoSpeed => 6,
+            Self::SosReiAndIsoSpeed => 7,
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl From<u16> for SensitivityType {
+    fn from(value: u16) -> Self {
+        Self::from_u16(value)
+    }
+}
+
+impl From<SensitivityType> for u16 {
+    fn from(sensitivity_type: SensitivityType) -> Self {
+        sensitivity_type.to_u16()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_recommended_exposure_index() {
+        assert_eq!(
+            SensitivityType::from_u16(2),
+            SensitivityType::RecommendedExposureIndex
+        );
+        assert_eq!(SensitivityType::RecommendedExposureIndex.to_u16(), 2);
+    }
+
+    #[test]
+    fn round_trips_defined_values() {
+        for value in 0..=7 {
+            let sensitivity_type = SensitivityType::from(value);
+            assert!(!matches!(sensitivity_type, SensitivityType::Other(_)));
+            assert_eq!(u16::from(sensitivity_type), value);
+        }
+        assert_eq!(SensitivityType::default().to_u16(), 0);
+    }
+
+    #[test]
+    fn keeps_undefined_values() {
+        assert_eq!(SensitivityType::from_u16(8), SensitivityType::Other(8));
+        assert_eq!(SensitivityType::Other(8).to_u16(), 8);
+    }
+}
@@ -0,0 +1,66 @@
+//! Decoding of the `InkSet` (`0x014C`) tag of CMYK separated images.
+
+/// The set of inks used in a separated (`PhotometricInterpretation` = 5)
+/// image, as given by the `InkSet` tag.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum InkSet {
+    /// Cyan, magenta, yellow and black, in that order (the default). The
+    /// `InkNames` tag is not needed.
+    #[default]
+    Cmyk,
+    /// An ink set other than CMYK, named by the `InkNames` tag.
+    NotCmyk,
+    /// A value not defined by the TIFF specification.
+    Other(u16),
+}
+
+impl InkSet {
+    /// Decodes an `InkSet` value.
+    pub const fn from_u16(value: u16) -> Self {
+        match value {
+            1 => Self::Cmyk,
+            2 => Self::NotCmyk,
+            _ => Self::Other(value),
+        }
+    }
+
+    /// Encodes this back into an `InkSet` value.
+    pub const fn to_u16(self) -> u16 {
+        match self {
+            Self::Cmyk => 1,
+            Self::NotCmyk => 2,
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl From<u16> for InkSet {
+    fn from(value: u16) -> Self {
+        Self::from_u16(value)
+    }
+}
+
+impl From<InkSet> for u16 {
+    fn from(ink_set: InkSet) -> Self {
+        ink_set.to_u16()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_both_ink_sets() {
+        assert_eq!(InkSet::from_u16(1), InkSet::Cmyk);
+        assert_eq!(InkSet::from_u16(2), InkSet::NotCmyk);
+        assert_eq!(InkSet::default().to_u16(), 1);
+        assert_eq!(u16::from(InkSet::NotCmyk), 2);
+    }
+
+    #[test]
+    fn keeps_undefined_values() {
+        assert_eq!(InkSet::from(0), InkSet::Other(0));
+        assert_eq!(InkSet::Other(3).to_u16(), 3);
+    }
+}
@@ -0,0 +1,214 @@
+//! Decoding of the `NewSubfileType` (`0x00FE`) bit field and the deprecated
+//! `SubfileType` (`0x00FF`) tag it replaces.
+
+/// Bit 0: the image is a reduced-resolution version of another image.
+const REDUCED_RESOLUTION: u32 = 1 << 0;
+
+/// Bit 1: the image is a single page of a multi-page image.
+const SINGLE_PAGE: u32 = 1 << 1;
+
+/// Bit 2: the image defines a transparency mask for another image.
+const TRANSPARENCY_MASK: u32 = 1 << 2;
+
+/// The bits decoded into named flags of [`SubfileType`].
+const KNOWN: u32 = REDUCED_RESOLUTION | SINGLE_PAGE | TRANSPARENCY_MASK;
+
+/// The kind of data held by an IFD, as given by its `NewSubfileType` tag.
+///
+/// The default value (all bits unset) describes a primary, full-resolution
+/// image, which is also what an absent `NewSubfileType` tag means. Bits this
+/// type has no flag for (such as those assigned by DNG) are kept in
+/// [`other`](Self::other), so decoding and encoding is lossless.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SubfileType {
+    /// The image is a reduced-resolution version (thumbnail or preview) of
+    /// another image in the file.
+    pub reduced_resolution: bool,
+    /// The image is a single page of a multi-page image.
+    pub single_page: bool,
+    /// The image defines a transparency mask for another image in the file.
+    pub transparency_mask: bool,
+    /// The remaining bits of the value, with the bits of the flags above
+    /// cleared.
+    pub other: u32,
+}
+
+impl SubfileType {
+    /// Decodes a `NewSubfileType` value.
+    pub const fn from_bits(bits: u32) -> Self {
+        Self {
+            reduced_resolution: bits & REDUCED_RESOLUTION != 0,
+            single_page: bits & SINGLE_PAGE != 0,
+            transparency_mask: bits & TRANSPARENCY_MASK != 0,
+            other: bits & !KNOWN,
+        }
+    }
+
+    /// Encodes the flags back into a `NewSubfileType` value.
+    pub const fn to_bits(self) -> u32 {
+        let mut bits = self.other & !KNOWN;
+        if self.reduced_resolution {
+            bits |= REDUCED_RESOLUTION;
+        }
+        if self.single_page {
+            bits |= SINGLE_PAGE;
+        }
+        if self.transparency_mask {
+            bits |= TRANSPARENCY_MASK;
+        }
+        bits
+    }
+
+    /// Returns whether this describes a primary, full-resolution image, i.e.
+    /// no bits other than the single-page flag are set.
+    pub const fn is_primary(self) -> bool {
+        !self.reduced_resolution && !self.transparency_mask && self.other == 0
+    }
+}
+
+impl From<u32> for SubfileType {
+    fn from(bits: u32) -> Self {
+        Self::from_bits(bits)
+    }
+}
+
+impl From<SubfileType> for u32 {
+    fn from(subfile_type: SubfileType) -> Self {
+        subfile_type.to_bits()
+    }
+}
+
+/// The kind of data held by an IFD, as given by the deprecated `SubfileType`
+/// tag.
+///
+/// Writers should use `NewSubfileType` instead; [`to_new_subfile_type`]
+/// converts a value read from an older file.
+///
+/// [`to_new_subfile_type`]: Self::to_new_subfile_type
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LegacySubfileType {
+    /// Full-resolution image data.
+    FullResolution,
+    /// Reduced-resolution image data.
+    ReducedResolution,
+    /// A single page of a multi-page image.
+    Page,
+    /// A value not defined by the TIFF specification.
+    Other(u16),
+}
+
+impl LegacySubfileType {
+    /// Decodes a `SubfileType` value.
+    pub const fn from_u16(value: u16) -> Self {
+        match value {
+            1 => Self::FullResolution,
+            2 => Self::ReducedResolution,
+            3 => Self::Page,
+            _ => Self::Other(value),
+        }
+    }
+
+    /// Encodes this back into a `SubfileType` value.
+    pub const fn to_u16(self) -> u16 {
+        match self {
+            Self::FullResolution => 1,
+            Self::ReducedResolution => 2,
+            Self::Page => 3,
+            Self::Other(value) => value,
+        }
+    }
+
+    /// Returns the equivalent `NewSubfileType` flags.
+    ///
+    /// Full-resolution and undefined values map to the default, primary
+    /// image.
+    pub const fn to_new_subfile_type(self) -> SubfileType {
+        SubfileType {
+            reduced_resolution: matches!(self, Self::ReducedResolution),
+            single_page: matches!(self, Self::Page),
+            transparency_mask: false,
+            other: 0,
+        }
+    }
+}
+
+impl From<u16> for LegacySubfileType {
+    fn from(value: u16) -> Self {
+        Self::from_u16(value)
+    }
+}
+
+impl From<LegacySubfileType> for u16 {
+    fn from(subfile_type: LegacySubfileType) -> Self {
+        subfile_type.to_u16()
+    }
+}
+
+impl From<LegacySubfileType> for SubfileType {
+    fn from(subfile_type: LegacySubfileType) -> Self {
+        subfile_type.to_new_subfile_type()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_primary_image() {
+        let subfile_type = SubfileType::from_bits(0);
+        assert_eq!(subfile_type, SubfileType::default());
+        assert!(subfile_type.is_primary());
+    }
+
+    #[test]
+    fn decodes_reduced_resolution() {
+        let subfile_type = SubfileType::from_bits(1);
+        assert!(subfile_type.reduced_resolution);
+        assert!(!subfile_type.single_page);
+        assert!(!subfile_type.transparency_mask);
+        assert!(!subfile_type.is_primary());
+    }
+
+    #[test]
+    fn round_trips_bits() {
+        for bits in 0..8 {
+            assert_eq!(SubfileType::from_bits(bits).to_bits(), bits);
+        }
+        for bits in [0x8, 0x10, 0x8000_0000, 0xFFFF_FFFF] {
+            assert_eq!(u32::from(SubfileType::from(bits)), bits);
+        }
+    }
+
+    #[test]
+    fn keeps_unrecognized_bits() {
+        // DNG assigns bit 3 to depth maps.
+        let subfile_type = SubfileType::from_bits(0b1000);
+        assert_eq!(subfile_type.other, 0b1000);
+        assert!(!subfile_type.reduced_resolution);
+        assert!(!subfile_type.is_primary());
+        assert!(SubfileType::from_bits(0b10).is_primary());
+    }
+
+    #[test]
+    fn round_trips_legacy_values() {
+        for value in 1..=3 {
+            assert_eq!(LegacySubfileType::from_u16(value).to_u16(), value);
+        }
+        assert_eq!(LegacySubfileType::from(4), LegacySubfileType::Other(4));
+        assert_eq!(u16::from(LegacySubfileType::Other(4)), 4);
+    }
+
+    #[test]
+    fn converts_legacy_values() {
+        let reduced = SubfileType::from(LegacySubfileType::from_u16(2));
+        assert!(reduced.reduced_resolution);
+        assert_eq!(reduced.to_bits(), 1);
+        let page = LegacySubfileType::Page.to_new_subfile_type();
+        assert!(page.single_page);
+        assert_eq!(page.to_bits(), 2);
+        let full = LegacySubfileType::FullResolution.to_new_subfile_type();
+        assert!(full.is_primary());
+        assert_eq!(full.to_bits(), 0);
+    }
+}
@@ -0,0 +1,216 @@
+//! Evaluation of DNG `ProfileToneCurve` (`0xC6FC`) tone curves.
+//!
+//! A tone curve is a list of `(input, output)` control points in `[0, 1]`,
+//! with strictly increasing inputs, starting at `(0, 0)` and ending at
+//! `(1, 1)`. The DNG specification requires the curve to be interpolated with
+//! a cubic spline; the spline here matches the one used by the Adobe DNG SDK,
+//! so results agree with reference converters.
+
+/// A tone curve prepared for repeated evaluation.
+///
+/// Solving the spline is linear in the number of control points, so a curve
+/// that is applied per pixel should be built once with [`ToneCurve::new`] and
+/// then evaluated with [`ToneCurve::evaluate`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ToneCurve {
+    /// The control point inputs, strictly increasing.
+    xs: Vec<f64>,
+    /// The control point outputs.
+    ys: Vec<f64>,
+    /// The spline slope at each control point.
+    slopes: Vec<f64>,
+}
+
+impl ToneCurve {
+    /// Prepares the spline through `curve`.
+    ///
+    /// Returns `None` if the curve has fewer than two points, does not start
+    /// at `(0, 0)` and end at `(1, 1)`, has a non-finite point, or its inputs
+    /// are not strictly increasing. Such curves are invalid per the DNG
+    /// specification.
+    pub fn new(curve: &[(f32, f32)]) -> Option<Self> {
+        let (&first, &last) = (curve.first()?, curve.last()?);
+        if curve.len() < 2 || first != (0.0, 0.0) || last != (1.0, 1.0) {
+            return None;
+        }
+        if curve.iter().any(|&(x, y)| !x.is_finite() || !y.is_finite()) {
+            return None;
+        }
+        if curve.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+            return None;
+        }
+
+        let xs: Vec<f64> = curve.iter().map(|&(x, _)| f64::from(x)).collect();
+        let ys: Vec<f64> = curve.iter().map(|&(_, y)| f64::from(y)).collect();
+        let slopes = solve_slopes(&xs, &ys);
+
+        Some(Self { xs, ys, slopes })
+    }
+
+    /// Evaluates the curve at `input`, clamped to `[0, 1]`. A NaN input maps
+    /// to the start of the curve.
+    pub fn evaluate(&self, input: f32) -> f32 {
+        let x = f64::from(input.clamp(0.0, 1.0));
+        let count = self.xs.len();
+        if x.is_nan() || x <= self.xs[0] {
+            return self.ys[0] as f32;
+        }
+        if x >= self.xs[count - 1] {
+            return self.ys[count - 1] as f32;
+        }
+
+        // The first control point with an input of at least `x`; `x` then lies
+        // in the segment `(xs[j - 1], xs[j]]`.
+        let j = self.xs.partition_point(|&xj| xj < x);
+        if self.xs[j] == x {
+            return self.ys[j] as f32;
+        }
+
+        let (x0, x1) = (self.xs[j - 1], self.xs[j]);
+        let (y0, y1) = (self.ys[j - 1], self.ys[j]);
+        let (s0, s1) = (self.slopes[j - 1], self.slopes[j]);
+
+        let width = x1 - x0;
+        let b = (x - x0) / width;
+        let c = (x1 - x) / width;
+        let y = (y0 * (2.0 - c + b) + s0 * width * b) * (c * c)
+            + (y1 * (2.0 - b + c) - s1 * width * c) * (b * b);
+
+        y as f32
+    }
+}
+
+/// Evaluates the tone curve `curve` at `input`.
+///
+/// `input` is clamped to `[0, 1]`, with NaN mapping to `0`. An invalid curve
+/// (see [`ToneCurve::new`]) is treated as the identity curve, as DNG readers
+/// ignore such curves.
+///
+/// This solves the spline on every call; use [`ToneCurve`] to evaluate the
+/// same curve many times.
+pub fn eval_tone_curve(curve: &[(f32, f32)], input: f32) -> f32 {
+    match ToneCurve::new(curve) {
+        Some(curve) => curve.evaluate(input),
+        None if input.is_nan() => 0.0,
+        None => input.clamp(0.0, 1.0),
+    }
+}
+
+/// Computes the slope of the spline at each control point.
+///
+/// Initial slopes are the weighted average of the slopes to the neighboring
+/// points, which are then refined by solving the tridiagonal system for a
+/// smooth (C2-continuous) spline.
+fn solve_slopes(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    let count = xs.len();
+    let mut slopes = vec![0.0; count];
+
+    let mut a = xs[1] - xs[0];
+    let mut b = (ys[1] - ys[0]) / a;
+    slopes[0] = b;
+    for j in 2..count {
+        let c = xs[j] - xs[j - 1];
+        let d = (ys[j] - ys[j - 1]) / c;
+        slopes[j - 1] = (b * c + d * a) / (a + c);
+        a = c;
+        b = d;
+    }
+    slopes[count - 1] = 2.0 * b - slopes[count - 2];
+    slopes[0] = 2.0 * slopes[0] - slopes[1];
+
+    if count > 2 {
+        let mut e = vec![0.0; count];
+        let mut f = vec![0.0; count];
+        let mut g = vec![0.0; count];
+
+        f[0] = 0.5;
+        e[count - 1] = 0.5;
+        g[0] = 0.75 * (slopes[0] + slopes[1]);
+        g[count - 1] = 0.75 * (slopes[count - 2] + slopes[count - 1]);
+        for j in 1..count - 1 {
+            let span = (xs[j + 1] - xs[j - 1]) * 2.0;
+            e[j] = (xs[j + 1] - xs[j]) / span;
+            f[j] = (xs[j] - xs[j - 1]) / span;
+            g[j] = 1.5 * slopes[j];
+        }
+
+        for j in 1..count {
+            let pivot = 1.0 - f[j - 1] * e[j];
+            if j != count - 1 {
+                f[j] /= pivot;
+            }
+            g[j] = (g[j] - g[j - 1] * e[j]) / pivot;
+        }
+        for j in (0..count - 1).rev() {
+            g[j] -= f[j] * g[j + 1];
+        }
+
+        slopes = g;
+    }
+
+    slopes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CURVE: [(f32, f32); 3] = [(0.0, 0.0), (0.5, 0.75), (1.0, 1.0)];
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "expected {expected}, got {actual}",
+        );
+    }
+
+    #[test]
+    fn passes_through_control_points() {
+        for &(x, y) in &CURVE {
+            assert_close(eval_tone_curve(&CURVE, x), y);
+        }
+    }
+
+    #[test]
+    fn interpolates_between_control_points() {
+        // Slopes for this curve solve to 1.75, 1 and 0.25.
+        assert_close(eval_tone_curve(&CURVE, 0.25), 0.421875);
+        assert_close(eval_tone_curve(&CURVE, 0.75), 0.921875);
+    }
+
+    #[test]
+    fn clamps_input() {
+        assert_close(eval_tone_curve(&CURVE, -0.5), 0.0);
+        assert_close(eval_tone_curve(&CURVE, 1.5), 1.0);
+    }
+
+    #[test]
+    fn maps_nan_input_to_curve_start() {
+        assert_close(eval_tone_curve(&CURVE, f32::NAN), 0.0);
+        assert_close(eval_tone_curve(&[(0.0, 0.0), (1.0, 0.5)], f32::NAN), 0.0);
+    }
+
+    #[test]
+    fn two_point_curve_is_identity() {
+        let curve = [(0.0, 0.0), (1.0, 1.0)];
+        for x in [0.0, 0.1, 0.33, 0.5, 0.9, 1.0] {
+            assert_close(eval_tone_curve(&curve, x), x);
+        }
+    }
+
+    #[test]
+    fn invalid_curves_are_identity() {
+        assert!(ToneCurve::new(&[]).is_none());
+        assert!(ToneCurve::new(&[(0.0, 0.0)]).is_none());
+        assert!(ToneCurve::new(&[(0.0, 0.1), (1.0, 1.0)]).is_none());
+        assert!(ToneCurve::new(&[(0.0, 0.0), (0.5, 0.2), (0.5, 0.3), (1.0, 1.0)]).is_none());
+        assert!(ToneCurve::new(&[(0.0, 0.0), (f32::NAN, 0.5), (1.0, 1.0)]).is_none());
+        assert!(ToneCurve::new(&[(0.0, 0.0), (0.5, f32::INFINITY), (1.0, 1.0)]).is_none());
+
+        assert_close(eval_tone_curve(&[(0.0, 0.0), (1.0, 0.5)], 0.3), 0.3);
+        assert_close(
+            eval_tone_curve(&[(0.0, 0.0), (f32::NAN, 0.5), (1.0, 1.0)], 0.3),
+            0.3,
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! TIFF field types used to store EXIF tag values.
+
+use std::error::Error;
+use std::fmt;
+
+/// The on-disk field type of an IFD entry, with discriminants equal to the
+/// type codes stored in the entry.
+///
+/// This covers the TIFF 6.0 types, the BigTIFF 64-bit additions and the
+/// `UTF-8` type introduced by Exif 3.0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum ExifType {
+    /// An 8-bit unsigned integer.
+    Byte = 1,
+    /// An 8-bit byte holding a 7-bit ASCII code; the last byte must be NUL.
+    Ascii = 2,
+    /// A 16-bit unsigned integer.
+    Short = 3,
+    /// A 32-bit unsigned integer.
+    Long = 4,
+    /// Two `Long`s: the numerator and the denominator of a fraction.
+    Rational = 5,
+    /// An 8-bit signed (two's complement) integer.
+    SByte = 6,
+    /// An 8-bit byte that may hold anything, depending on the tag.
+    Undefined = 7,
+    /// A 16-bit signed (two's complement) integer.
+    SShort = 8,
+    /// A 32-bit signed (two's complement) integer.
+    SLong = 9,
+    /// Two `SLong`s: the numerator and the denominator of a fraction.
+    SRational = 10,
+    /// A single precision (4-byte) IEEE floating point number.
+    Float = 11,
+    /// A double precision (8-byte) IEEE floating point number.
+    Double = 12,
+    /// A 32-bit unsigned offset to a child IFD.
+    Ifd = 13,
+    /// A 64-bit unsigned integer (BigTIFF).
+    Long8 = 16,
+    /// A 64-bit signed (two's complement) integer (BigTIFF).
+    SLong8 = 17,
+    /// A 64-bit unsigned offset to a child IFD (BigTIFF).
+    Ifd8 = 18,
+    /// An 8-bit byte of a UTF-8 string; the last byte must be NUL (Exif 3.0).
+    Utf8 = 129,
+}
+
+impl ExifType {
+    /// Returns the type code stored in an IFD entry for this type.
+    pub const fn code(self) -> u16 {
+        self as u16
+    }
+
+    /// Returns the size in bytes of a single value of this type.
+    ///
+    /// The size of an entry's value is this times the entry's count.
+    pub const fn byte_size(self) -> usize {
+        match self {
+            Self::Byte | Self::Ascii | Self::SByte | Self::Undefined | Self::Utf8 => 1,
+            Self::Short | Self::SShort => 2,
+            Self::Long | Self::SLong | Self::Float | Self::Ifd => 4,
+            Self::Rational | Self::SRational | Self::Double => 8,
+            Self::Long8 | Self::SLong8 | Self::Ifd8 => 8,
+        }
+    }
+}
+
+impl From<ExifType> for u16 {
+    fn from(ty: ExifType) -> Self {
+        ty.code()
+    }
+}
+
+impl TryFrom<u16> for ExifType {
+    type Error = UnknownExifType;
+
+    fn try_from(code: u16) -> Result<Self, Self::Error> {
+        Ok(match code {
+            1 => Self::Byte,
+            2 => Self::Ascii,
+            3 => Self::Short,
+            4 => Self::Long,
+            5 => Self::Rational,
+            6 => Self::SByte,
+            7 => Self::Undefined,
+            8 => Self::SShort,
+            9 => Self::SLong,
+            10 => Self::SRational,
+            11 => Self::Float,
+            12 => Self::Double,
+            13 => Self::Ifd,
+            16 => Self::Long8,
+            17 => Self::SLong8,
+            18 => Self::Ifd8,
+            129 => Self::Utf8,
+            _ => return Err(UnknownExifType(code)),
+        })
+    }
+}
+
+/// The error returned when converting an unassigned type code to an
+/// [`ExifType`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UnknownExifType(pub u16);
+
+impl fmt::Display for UnknownExifType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown TIFF field type code {}", self.0)
+    }
+}
+
+impl Error for UnknownExifType {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [ExifType; 17] = [
+        ExifType::Byte,
+        ExifType::Ascii,
+        ExifType::Short,
+        ExifType::Long,
+        ExifType::Rational,
+        ExifType::SByte,
+        ExifType::Undefined,
+        ExifType::SShort,
+        ExifType::SLong,
+        ExifType::SRational,
+        ExifType::Float,
+        ExifType::Double,
+        ExifType::Ifd,
+        ExifType::Long8,
+        ExifType::SLong8,
+        ExifType::Ifd8,
+        ExifType::Utf8,
+    ];
+
+    #[test]
+    fn codes_match_spec() {
+        assert_eq!(ExifType::Byte.code(), 1);
+        assert_eq!(ExifType::Ascii.code(), 2);
+        assert_eq!(ExifType::Rational.code(), 5);
+        assert_eq!(ExifType::Undefined.code(), 7);
+        assert_eq!(ExifType::SRational.code(), 10);
+        assert_eq!(ExifType::Ifd.code(), 13);
+        assert_eq!(ExifType::Ifd8.code(), 18);
+        assert_eq!(u16::from(ExifType::Utf8), 129);
+    }
+
+    #[test]
+    fn byte_sizes_match_spec() {
+        let sizes = [1, 1, 2, 4, 8, 1, 1, 2, 4, 8, 4, 8, 4, 8, 8, 8, 1];
+        for (ty, size) in ALL.into_iter().zip(sizes) {
+            assert_eq!(ty.byte_size(), size, "{ty:?}");
+        }
+    }
+
+    #[test]
+    fn round_trips_codes() {
+        for ty in ALL {
+            assert_eq!(ExifType::try_from(ty.code()), Ok(ty));
+        }
+        for code in [0, 14, 15, 19, 128, 130, u16::MAX] {
+            assert_eq!(ExifType::try_from(code), Err(UnknownExifType(code)));
+        }
+    }
+}